@@ -0,0 +1,193 @@
+//! Minimal in-memory model of a parsed Bond schema, walked by the [`v2::Visitor`] trait to
+//! emit generated Rust source.
+
+pub mod v2;
+
+/// A parsed Bond schema file: a flat list of struct/enum declarations.
+pub struct Schema {
+    declarations: Vec<UserType>,
+}
+
+impl Schema {
+    /// Creates a schema from its top-level declarations.
+    pub fn new(declarations: Vec<UserType>) -> Self {
+        Self { declarations }
+    }
+
+    /// Returns the schema's top-level struct/enum declarations.
+    pub fn declarations(&self) -> &Vec<UserType> {
+        &self.declarations
+    }
+}
+
+/// A top-level Bond declaration: either a struct or an enum.
+pub enum UserType {
+    Struct(Struct),
+    Enum(Enum),
+}
+
+/// A Bond struct declaration.
+pub struct Struct {
+    name: String,
+    base: Option<Type>,
+    fields: Vec<Field>,
+    attributes: Vec<Attribute>,
+}
+
+impl Struct {
+    /// Creates a struct declaration named `name`.
+    pub fn new(name: impl Into<String>, base: Option<Type>, fields: Vec<Field>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            name: name.into(),
+            base,
+            fields,
+            attributes,
+        }
+    }
+
+    /// Returns the declaration's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the base type this struct inherits from, if any.
+    pub fn base(&self) -> Option<&Type> {
+        self.base.as_ref()
+    }
+
+    /// Returns the struct's fields.
+    pub fn fields(&self) -> &Vec<Field> {
+        &self.fields
+    }
+
+    /// Returns the struct's attributes.
+    pub fn attributes(&self) -> &Vec<Attribute> {
+        &self.attributes
+    }
+}
+
+/// A Bond enum declaration.
+pub struct Enum {
+    name: String,
+    constants: Vec<EnumConstant>,
+    attributes: Vec<Attribute>,
+}
+
+impl Enum {
+    /// Creates an enum declaration named `name`.
+    pub fn new(name: impl Into<String>, constants: Vec<EnumConstant>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            name: name.into(),
+            constants,
+            attributes,
+        }
+    }
+
+    /// Returns the declaration's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the enum's constants.
+    pub fn constants(&self) -> &Vec<EnumConstant> {
+        &self.constants
+    }
+
+    /// Returns the enum's attributes.
+    pub fn attributes(&self) -> &Vec<Attribute> {
+        &self.attributes
+    }
+}
+
+/// A single named constant of an [`Enum`].
+pub struct EnumConstant {
+    name: String,
+    value: i32,
+}
+
+impl EnumConstant {
+    /// Creates a constant named `name` with ordinal `value`.
+    pub fn new(name: impl Into<String>, value: i32) -> Self {
+        Self { name: name.into(), value }
+    }
+
+    /// Returns the constant's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the constant's ordinal value.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+/// A field of a [`Struct`].
+pub struct Field {
+    name: String,
+    field_type: Type,
+}
+
+impl Field {
+    /// Creates a field named `name` with type `field_type`.
+    pub fn new(name: impl Into<String>, field_type: Type) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+        }
+    }
+
+    /// Returns the field's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the field's type.
+    pub fn field_type(&self) -> &Type {
+        &self.field_type
+    }
+}
+
+/// The type of a [`Field`] or a [`Struct`]'s base, named after its Bond keyword
+/// (e.g. `"string"`, `"double"`, `"int32"`).
+pub struct Type {
+    name: String,
+}
+
+impl Type {
+    /// Creates a type named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Returns the type's Bond keyword name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A Bond attribute attached to a [`Struct`] or [`Enum`] declaration, e.g. `[Event]`.
+pub struct Attribute {
+    name: String,
+    value: Option<String>,
+}
+
+impl Attribute {
+    /// Creates an attribute named `name` with an optional value.
+    pub fn new(name: impl Into<String>, value: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+
+    /// Returns the attribute's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the attribute's value, if any.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
@@ -0,0 +1,35 @@
+//! Walks a parsed Bond [`Schema`](crate::bond::Schema) with a [`Visitor`](super::Visitor) to
+//! emit the `appinsights` contracts module (`Envelope`, `Data`, and friends) that ships with
+//! the SDK.
+
+use crate::bond::*;
+use crate::bond::v2::Visitor;
+
+/// Emits a Rust struct for every [`Struct`] declaration in a schema; no builder is emitted.
+#[derive(Default)]
+pub struct ContractsVisitor {
+    generated: String,
+}
+
+impl ContractsVisitor {
+    /// Creates an empty visitor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the Rust source generated so far.
+    pub fn generated(&self) -> &str {
+        &self.generated
+    }
+}
+
+impl Visitor for ContractsVisitor {
+    fn visit_struct(&mut self, declaration: &Struct) {
+        self.generated.push_str(&format!("pub struct {} {{\n", declaration.name()));
+        for field in declaration.fields() {
+            self.generated
+                .push_str(&format!("    pub {}: {},\n", field.name(), field.field_type().name()));
+        }
+        self.generated.push_str("}\n\n");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod compiler;
+pub mod telemetry_codegen;
 
 use crate::bond::*;
 
@@ -29,7 +30,7 @@ pub trait Visitor {
         self.visit_struct_attributes(declaration.attributes());
     }
 
-    fn visit_base(&mut self, declaration: &Type) {}
+    fn visit_base(&mut self, _declaration: &Type) {}
 
     fn visit_fields(&mut self, fields: &Vec<Field>) {
         for field in fields {
@@ -37,7 +38,7 @@ pub trait Visitor {
         }
     }
 
-    fn visit_field(&mut self, field: &Field) {}
+    fn visit_field(&mut self, _field: &Field) {}
 
     fn visit_struct_attributes(&mut self, attributes: &Vec<Attribute>) {
         for attribute in attributes {
@@ -45,7 +46,7 @@ pub trait Visitor {
         }
     }
 
-    fn visit_struct_attribute(&mut self, attribute: &Attribute) {}
+    fn visit_struct_attribute(&mut self, _attribute: &Attribute) {}
 
     fn visit_enum(&mut self, declaration: &Enum) {
         self.visit_enum_constants(declaration.constants());
@@ -58,7 +59,7 @@ pub trait Visitor {
         }
     }
 
-    fn visit_enum_constant(&mut self, constant: &EnumConstant) {}
+    fn visit_enum_constant(&mut self, _constant: &EnumConstant) {}
 
     fn visit_enum_attributes(&mut self, attributes: &Vec<Attribute>) {
         for attribute in attributes {
@@ -66,5 +67,5 @@ pub trait Visitor {
         }
     }
 
-    fn visit_enum_attribute(&mut self, attribute: &Attribute) {}
+    fn visit_enum_attribute(&mut self, _attribute: &Attribute) {}
 }
@@ -0,0 +1,323 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::bond::*;
+use crate::bond::v2::Visitor;
+
+/// Kinds of field a declared event may use, by Bond type; each maps to a measurement (numeric), a
+/// string property, or a boolean flag (also stored as a property, but with a `bool` setter)
+/// on the generated telemetry struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Measurement,
+    Property,
+    Flag,
+}
+
+fn field_kind(field_type: &Type) -> Option<FieldKind> {
+    match field_type.name() {
+        "double" | "int32" | "int64" | "uint32" | "uint64" => Some(FieldKind::Measurement),
+        "string" | "wstring" => Some(FieldKind::Property),
+        "bool" => Some(FieldKind::Flag),
+        _ => None,
+    }
+}
+
+/// A [`Visitor`] that, given a declarative schema of custom events, generates a Rust struct
+/// per event implementing the `Telemetry` trait with `Properties`/`Measurements` setters for
+/// each declared field, so users get compile-checked, self-documenting telemetry types
+/// instead of hand-building `Properties` maps.
+///
+/// Validates at generation time that every event declares unique field names and only uses
+/// field types this generator knows how to map onto `Properties`/`Measurements`.
+#[derive(Default)]
+pub struct TelemetryVisitor {
+    events: Vec<GeneratedEvent>,
+    errors: Vec<String>,
+}
+
+struct GeneratedEvent {
+    name: String,
+    fields: Vec<(String, FieldKind)>,
+}
+
+impl TelemetryVisitor {
+    /// Creates an empty visitor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns validation errors collected while visiting the schema, e.g. duplicate field
+    /// names or unsupported field types. Non-empty means [`TelemetryVisitor::generate`]
+    /// should not be trusted.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Renders the generated Rust source for every event visited so far.
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+
+        for event in &self.events {
+            let struct_name = event.name.clone();
+
+            writeln!(out, "pub struct {} {{", struct_name).unwrap();
+            writeln!(out, "    timestamp: chrono::DateTime<chrono::Utc>,").unwrap();
+            writeln!(out, "    tags: crate::telemetry::ContextTags,").unwrap();
+            writeln!(out, "    properties: crate::telemetry::Properties,").unwrap();
+            writeln!(out, "    measurements: crate::telemetry::Measurements,").unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+
+            writeln!(out, "impl {} {{", struct_name).unwrap();
+            writeln!(out, "    pub fn new() -> Self {{").unwrap();
+            writeln!(out, "        Self {{").unwrap();
+            writeln!(out, "            timestamp: crate::time::now(),").unwrap();
+            writeln!(out, "            tags: Default::default(),").unwrap();
+            writeln!(out, "            properties: Default::default(),").unwrap();
+            writeln!(out, "            measurements: Default::default(),").unwrap();
+            writeln!(out, "        }}").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+
+            for (field, kind) in &event.fields {
+                match kind {
+                    FieldKind::Measurement => {
+                        writeln!(out, "    pub fn set_{field}(&mut self, value: f64) -> &mut Self {{", field = field)
+                            .unwrap();
+                        writeln!(
+                            out,
+                            "        self.measurements.insert(\"{field}\".into(), value);",
+                            field = field
+                        )
+                        .unwrap();
+                    }
+                    FieldKind::Property => {
+                        writeln!(
+                            out,
+                            "    pub fn set_{field}(&mut self, value: String) -> &mut Self {{",
+                            field = field
+                        )
+                        .unwrap();
+                        writeln!(
+                            out,
+                            "        self.properties.insert(\"{field}\".into(), value);",
+                            field = field
+                        )
+                        .unwrap();
+                    }
+                    FieldKind::Flag => {
+                        writeln!(
+                            out,
+                            "    pub fn set_{field}(&mut self, value: bool) -> &mut Self {{",
+                            field = field
+                        )
+                        .unwrap();
+                        writeln!(
+                            out,
+                            "        self.properties.insert(\"{field}\".into(), value.to_string());",
+                            field = field
+                        )
+                        .unwrap();
+                    }
+                }
+                writeln!(out, "        self").unwrap();
+                writeln!(out, "    }}").unwrap();
+                writeln!(out).unwrap();
+            }
+
+            writeln!(out, "    pub fn record(self, client: &crate::TelemetryClient) {{").unwrap();
+            writeln!(out, "        client.track(self);").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+
+            writeln!(out, "impl crate::telemetry::Telemetry for {} {{", struct_name).unwrap();
+            writeln!(out, "    fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {{").unwrap();
+            writeln!(out, "        self.timestamp").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn properties(&self) -> &crate::telemetry::Properties {{").unwrap();
+            writeln!(out, "        &self.properties").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn properties_mut(&mut self) -> &mut crate::telemetry::Properties {{").unwrap();
+            writeln!(out, "        &mut self.properties").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn tags(&self) -> &crate::telemetry::ContextTags {{").unwrap();
+            writeln!(out, "        &self.tags").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn tags_mut(&mut self) -> &mut crate::telemetry::ContextTags {{").unwrap();
+            writeln!(out, "        &mut self.tags").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn measurements(&self) -> &crate::telemetry::Measurements {{").unwrap();
+            writeln!(out, "        &self.measurements").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    fn measurements_mut(&mut self) -> &mut crate::telemetry::Measurements {{").unwrap();
+            writeln!(out, "        &mut self.measurements").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+
+            writeln!(
+                out,
+                "impl From<(crate::context::TelemetryContext, {name})> for crate::contracts::Envelope {{",
+                name = struct_name
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "    fn from((context, telemetry): (crate::context::TelemetryContext, {name})) -> Self {{",
+                name = struct_name
+            )
+            .unwrap();
+            writeln!(out, "        use crate::telemetry::{{Combine, Telemetry}};").unwrap();
+            writeln!(out).unwrap();
+            writeln!(
+                out,
+                "        let data = crate::contracts::Data::EventData(crate::contracts::EventDataBuilder::new(\"{name}\")",
+                name = struct_name
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "            .properties(crate::telemetry::Properties::combine(context.properties, telemetry.properties))"
+            )
+            .unwrap();
+            writeln!(out, "            .measurements(telemetry.measurements)").unwrap();
+            writeln!(out, "            .build());").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "        let envelope_name = data.envelope_name(&context.normalized_i_key);").unwrap();
+            writeln!(
+                out,
+                "        let timestamp = telemetry.timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, true);"
+            )
+            .unwrap();
+            writeln!(out, "        let sample_rate = context.sampler.rate();").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "        crate::contracts::EnvelopeBuilder::new(envelope_name, timestamp)").unwrap();
+            writeln!(out, "            .data(crate::contracts::Base::Data(data))").unwrap();
+            writeln!(out, "            .i_key(context.i_key)").unwrap();
+            writeln!(out, "            .sample_rate(sample_rate)").unwrap();
+            writeln!(
+                out,
+                "            .tags(crate::telemetry::ContextTags::combine(context.tags, telemetry.tags))"
+            )
+            .unwrap();
+            writeln!(out, "            .build()").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+        }
+
+        out
+    }
+}
+
+impl Visitor for TelemetryVisitor {
+    fn visit_struct(&mut self, declaration: &Struct) {
+        let mut fields = Vec::new();
+        let mut seen = HashSet::new();
+
+        for field in declaration.fields() {
+            let name = field.name().to_string();
+            if !seen.insert(name.clone()) {
+                self.errors
+                    .push(format!("event `{}` declares field `{}` more than once", declaration.name(), name));
+                continue;
+            }
+
+            match field_kind(field.field_type()) {
+                Some(kind) => fields.push((name, kind)),
+                None => self.errors.push(format!(
+                    "event `{}` field `{}` has unsupported type `{}`",
+                    declaration.name(),
+                    name,
+                    field.field_type().name()
+                )),
+            }
+        }
+
+        self.events.push(GeneratedEvent {
+            name: declaration.name().to_string(),
+            fields,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visit(fields: Vec<(&str, &str)>) -> TelemetryVisitor {
+        let mut visitor = TelemetryVisitor::new();
+        let fields = fields
+            .into_iter()
+            .map(|(name, type_name)| Field::new(name, Type::new(type_name)))
+            .collect();
+        visitor.visit_struct(&Struct::new("PageView", None, fields, Vec::new()));
+        visitor
+    }
+
+    #[test]
+    fn it_emits_a_bool_setter_for_bool_fields() {
+        let visitor = visit(vec![("is_first_visit", "bool")]);
+
+        assert!(visitor.errors().is_empty());
+        let generated = visitor.generate();
+        assert!(
+            generated.contains("pub fn set_is_first_visit(&mut self, value: bool) -> &mut Self {"),
+            "generated code:\n{}",
+            generated
+        );
+        assert!(generated.contains("self.properties.insert(\"is_first_visit\".into(), value.to_string());"));
+    }
+
+    #[test]
+    fn it_emits_a_string_setter_for_string_fields() {
+        let visitor = visit(vec![("url", "string")]);
+
+        let generated = visitor.generate();
+        assert!(generated.contains("pub fn set_url(&mut self, value: String) -> &mut Self {"));
+        assert!(generated.contains("self.properties.insert(\"url\".into(), value);"));
+    }
+
+    #[test]
+    fn it_emits_an_f64_setter_for_numeric_fields() {
+        let visitor = visit(vec![("duration_ms", "double")]);
+
+        let generated = visitor.generate();
+        assert!(generated.contains("pub fn set_duration_ms(&mut self, value: f64) -> &mut Self {"));
+        assert!(generated.contains("self.measurements.insert(\"duration_ms\".into(), value);"));
+    }
+
+    #[test]
+    fn it_emits_the_telemetry_and_envelope_conversion_impls() {
+        let visitor = visit(vec![("url", "string")]);
+
+        let generated = visitor.generate();
+        assert!(generated.contains("impl crate::telemetry::Telemetry for PageView {"));
+        assert!(generated
+            .contains("impl From<(crate::context::TelemetryContext, PageView)> for crate::contracts::Envelope {"));
+    }
+
+    #[test]
+    fn it_rejects_duplicate_field_names() {
+        let visitor = visit(vec![("url", "string"), ("url", "string")]);
+
+        assert_eq!(visitor.errors().len(), 1);
+        assert!(visitor.errors()[0].contains("declares field `url` more than once"));
+    }
+
+    #[test]
+    fn it_rejects_unsupported_field_types() {
+        let visitor = visit(vec![("ids", "list<string>")]);
+
+        assert_eq!(visitor.errors().len(), 1);
+        assert!(visitor.errors()[0].contains("unsupported type `list<string>`"));
+    }
+}
@@ -0,0 +1,173 @@
+//! Buffers telemetry submitted through a [`TelemetryClient`](crate::TelemetryClient), applying
+//! the configured sampling decision as items are submitted, until the caller flushes it (there
+//! is no scheduler in this crate; callers flush on whatever cadence suits them, e.g. the
+//! interval from [`TelemetryConfig`](crate::TelemetryConfig)).
+
+use std::sync::Mutex;
+
+use crate::contracts::Envelope;
+use crate::telemetry::{AlertRule, Telemetry};
+
+/// Sends a batch of envelopes to their destination (the ingestion endpoint in production, an
+/// in-memory sink in tests). Kept generic so this crate doesn't need to pick an HTTP client
+/// on the caller's behalf.
+pub trait Transmitter: Send + Sync {
+    /// Sends a batch of envelopes, reporting delivery failures to stderr since there's no
+    /// caller to propagate an error to from `flush`.
+    fn send(&self, envelopes: Vec<Envelope>);
+}
+
+/// A [`Transmitter`] that discards everything it's given; the default until a real one is
+/// configured.
+#[derive(Default)]
+pub struct NoopTransmitter;
+
+impl Transmitter for NoopTransmitter {
+    fn send(&self, _envelopes: Vec<Envelope>) {}
+}
+
+/// Buffers submitted telemetry until the next flush, applying sampling on submission.
+pub struct TelemetryChannel {
+    transmitter: Box<dyn Transmitter>,
+    buffer: Mutex<Vec<Envelope>>,
+    rules: Mutex<Vec<AlertRule>>,
+}
+
+impl TelemetryChannel {
+    /// Creates a channel that transmits through `transmitter`.
+    pub fn new(transmitter: impl Transmitter + 'static) -> Self {
+        Self {
+            transmitter: Box::new(transmitter),
+            buffer: Mutex::new(Vec::new()),
+            rules: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers an [`AlertRule`] to observe every item submitted through this channel.
+    pub fn register(&self, rule: AlertRule) {
+        self.rules.lock().unwrap().push(rule);
+    }
+
+    /// Applies the sampling decision for `telemetry` and, if kept, converts and buffers it
+    /// for the next flush. Dropped items never reach the buffer.
+    ///
+    /// Every submitted item, sampled out or not, is still recorded against the channel's
+    /// registered alert rules: alerting should reflect what actually happened, not just the
+    /// sampled subset that got transmitted.
+    pub fn submit<E>(&self, context: crate::context::TelemetryContext, telemetry: E)
+    where
+        E: Telemetry,
+        Envelope: From<(crate::context::TelemetryContext, E)>,
+    {
+        for rule in self.rules.lock().unwrap().iter_mut() {
+            rule.observe(&telemetry);
+        }
+
+        if context.sampler().sample(&telemetry.sampling_key()).is_none() {
+            return;
+        }
+
+        let envelope = Envelope::from((context, telemetry));
+        self.buffer.lock().unwrap().push(envelope);
+    }
+
+    /// Transmits and clears the buffer, then evaluates registered alert rules against the
+    /// samples in their rolling windows, which flushing does not reset. Keeping predicate
+    /// evaluation out of `submit` keeps the ingestion hot path cheap.
+    pub fn flush(&self) {
+        let envelopes = std::mem::take(&mut *self.buffer.lock().unwrap());
+        if !envelopes.is_empty() {
+            self.transmitter.send(envelopes);
+        }
+
+        for rule in self.rules.lock().unwrap().iter_mut() {
+            rule.evaluate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration as StdDuration;
+
+    use super::*;
+    use crate::context::TelemetryContext;
+    use crate::telemetry::AvailabilityTelemetry;
+
+    #[derive(Default)]
+    struct RecordingTransmitter {
+        sent: Mutex<Vec<Envelope>>,
+    }
+
+    impl Transmitter for Arc<RecordingTransmitter> {
+        fn send(&self, envelopes: Vec<Envelope>) {
+            self.sent.lock().unwrap().extend(envelopes);
+        }
+    }
+
+    #[test]
+    fn it_drops_sampled_out_telemetry_before_it_reaches_the_buffer() {
+        let transmitter = Arc::new(RecordingTransmitter::default());
+        let channel = TelemetryChannel::new(transmitter.clone());
+
+        let context = TelemetryContext::with_sampler("instrumentation".into(), crate::telemetry::Sampler::new(0.0));
+        let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), true);
+        channel.submit(context, telemetry);
+        channel.flush();
+
+        assert!(transmitter.sent.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_keeps_telemetry_that_survives_sampling() {
+        let transmitter = Arc::new(RecordingTransmitter::default());
+        let channel = TelemetryChannel::new(transmitter.clone());
+
+        let context = TelemetryContext::new("instrumentation".into());
+        let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), true);
+        channel.submit(context, telemetry);
+        channel.flush();
+
+        assert_eq!(transmitter.sent.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_evaluates_registered_alert_rules_on_flush_not_on_submit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration as StdDuration;
+
+        use crate::telemetry::{AlertMethod, AlertRule, Predicate};
+
+        struct CountingAlert {
+            fired: Arc<AtomicUsize>,
+        }
+
+        impl AlertMethod for CountingAlert {
+            fn alert(&self, _name: &str, _observed: f64, _threshold: f64) {
+                self.fired.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let transmitter = Arc::new(RecordingTransmitter::default());
+        let channel = TelemetryChannel::new(transmitter.clone());
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        channel.register(AlertRule::new(
+            "availability",
+            StdDuration::from_secs(60),
+            Predicate::SuccessRateBelow(50.0),
+            CountingAlert { fired: fired.clone() },
+        ));
+
+        let context = TelemetryContext::new("instrumentation".into());
+        let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), false);
+        channel.submit(context, telemetry);
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        channel.flush();
+
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,75 @@
+//! The crate's entry point: submits telemetry through a [`TelemetryChannel`], stamped with a
+//! shared [`TelemetryContext`].
+
+use std::sync::Arc;
+
+use crate::channel::{NoopTransmitter, TelemetryChannel, Transmitter};
+use crate::config::TelemetryConfig;
+use crate::context::TelemetryContext;
+use crate::contracts::Envelope;
+use crate::telemetry::{AlertRule, Telemetry};
+
+/// Submits telemetry to Application Insights (or wherever its [`Transmitter`] sends it).
+///
+/// Cheap to clone: every clone shares the same channel, so it can be handed out freely to the
+/// code that produces telemetry. Each clone holds its own copy of the context, so changes made
+/// through [`TelemetryClient::context_mut`] apply to that clone only.
+#[derive(Clone)]
+pub struct TelemetryClient {
+    context: TelemetryContext,
+    channel: Arc<TelemetryChannel>,
+}
+
+impl TelemetryClient {
+    /// Creates a client for the given instrumentation key, with default configuration.
+    pub fn new(i_key: String) -> Self {
+        Self::from_config(TelemetryConfig::new(i_key))
+    }
+
+    /// Creates a client from a [`TelemetryConfig`], transmitting through `transmitter`.
+    pub fn with_transmitter(config: TelemetryConfig, transmitter: impl Transmitter + 'static) -> Self {
+        Self {
+            context: TelemetryContext::with_sampler(config.i_key().to_string(), config.sampler()),
+            channel: Arc::new(TelemetryChannel::new(transmitter)),
+        }
+    }
+
+    /// Creates a client from a [`TelemetryConfig`], discarding transmitted telemetry until a
+    /// real [`Transmitter`] is configured.
+    pub fn from_config(config: TelemetryConfig) -> Self {
+        Self::with_transmitter(config, NoopTransmitter)
+    }
+
+    /// Returns the shared context every telemetry item submitted through this client is
+    /// stamped with.
+    pub fn context(&self) -> &TelemetryContext {
+        &self.context
+    }
+
+    /// Returns a mutable reference to the shared context every telemetry item submitted
+    /// through this client is stamped with.
+    pub fn context_mut(&mut self) -> &mut TelemetryContext {
+        &mut self.context
+    }
+
+    /// Registers an [`AlertRule`] that observes every telemetry item submitted through this
+    /// client and fires when its predicate is crossed.
+    pub fn register_alert(&self, rule: AlertRule) {
+        self.channel.register(rule);
+    }
+
+    /// Submits a telemetry item, applying the configured sampling decision before buffering
+    /// it for the next flush.
+    pub fn track<E>(&self, telemetry: E)
+    where
+        E: Telemetry,
+        Envelope: From<(TelemetryContext, E)>,
+    {
+        self.channel.submit(self.context.clone(), telemetry);
+    }
+
+    /// Transmits buffered telemetry.
+    pub fn flush(&self) {
+        self.channel.flush();
+    }
+}
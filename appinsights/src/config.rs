@@ -0,0 +1,409 @@
+use std::time::Duration as StdDuration;
+
+use crate::telemetry::Sampler;
+
+#[cfg(feature = "config-file")]
+use std::path::Path;
+#[cfg(feature = "config-file")]
+use std::{env, fmt};
+
+#[cfg(feature = "config-file")]
+use serde::Deserialize;
+
+#[cfg(feature = "config-file")]
+const IKEY_VAR: &str = "APPINSIGHTS_IKEY";
+#[cfg(feature = "config-file")]
+const ENDPOINT_VAR: &str = "APPINSIGHTS_ENDPOINT";
+#[cfg(feature = "config-file")]
+const INTERVAL_VAR: &str = "APPINSIGHTS_INTERVAL";
+
+/// Controls behavior of the telemetry channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryConfig {
+    i_key: String,
+    endpoint: String,
+    interval: StdDuration,
+    sample_rate: f64,
+}
+
+impl TelemetryConfig {
+    /// Creates a new configuration with default endpoint, interval and sample rate for the
+    /// given instrumentation key.
+    pub fn new(i_key: String) -> Self {
+        Self {
+            i_key,
+            endpoint: "https://dc.services.visualstudio.com/v2/track".into(),
+            interval: StdDuration::from_secs(5),
+            sample_rate: 100.0,
+        }
+    }
+
+    /// Starts building a configuration for the given instrumentation key, additionally
+    /// exposing the `endpoint`, `interval` and `sample_rate` that [`TelemetryConfig::new`]
+    /// leaves at their defaults.
+    pub fn builder(i_key: String) -> TelemetryConfigBuilder {
+        TelemetryConfigBuilder::new(i_key)
+    }
+
+    /// Loads configuration from a JSON5, YAML, TOML or RON file at `path`, inferring the
+    /// format from its extension.
+    #[cfg(feature = "config-file")]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|err| ConfigError::Io(path.display().to_string(), err))?;
+
+        let raw: RawConfig = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json5") => json5::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?,
+            Some("yaml") | Some("yml") => {
+                serde_yaml::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?
+            }
+            Some("toml") => toml::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?,
+            Some("ron") => ron::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?,
+            Some(ext) => return Err(ConfigError::UnsupportedFormat(ext.to_string())),
+            None => return Err(ConfigError::UnsupportedFormat(String::new())),
+        };
+
+        Ok(Self::default().merge(raw))
+    }
+
+    /// Loads configuration from the `APPINSIGHTS_IKEY`, `APPINSIGHTS_ENDPOINT` and
+    /// `APPINSIGHTS_INTERVAL` environment variables, leaving unset values at their defaults.
+    #[cfg(feature = "config-file")]
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let raw = RawConfig {
+            i_key: env::var(IKEY_VAR).ok(),
+            endpoint: env::var(ENDPOINT_VAR).ok(),
+            interval: env::var(INTERVAL_VAR).ok(),
+            sample_rate: None,
+        };
+
+        Ok(Self::default().merge(raw))
+    }
+
+    /// Loads configuration by layering defaults, an optional config file and the
+    /// environment, in that order of increasing precedence.
+    #[cfg(feature = "config-file")]
+    pub fn load(file: Option<impl AsRef<Path>>) -> Result<Self, ConfigError> {
+        let mut config = match file {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+
+        let env = Self::from_env()?;
+        if env::var(IKEY_VAR).is_ok() {
+            config.i_key = env.i_key;
+        }
+        if env::var(ENDPOINT_VAR).is_ok() {
+            config.endpoint = env.endpoint;
+        }
+        if env::var(INTERVAL_VAR).is_ok() {
+            config.interval = env.interval;
+        }
+
+        Ok(config)
+    }
+
+    #[cfg(feature = "config-file")]
+    fn merge(mut self, raw: RawConfig) -> Self {
+        if let Some(i_key) = raw.i_key {
+            self.i_key = i_key;
+        }
+        if let Some(endpoint) = raw.endpoint {
+            self.endpoint = endpoint;
+        }
+        if let Some(interval) = raw.interval {
+            if let Ok(interval) = humantime::parse_duration(&interval) {
+                self.interval = interval;
+            }
+        }
+        if let Some(sample_rate) = raw.sample_rate {
+            self.sample_rate = sample_rate;
+        }
+
+        self
+    }
+
+    /// Returns the instrumentation key telemetry is submitted under.
+    pub fn i_key(&self) -> &str {
+        &self.i_key
+    }
+
+    /// Returns the ingestion endpoint telemetry is submitted to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the preferred interval between flushes. This crate doesn't schedule flushes
+    /// itself: callers that want periodic flushing read this value and call
+    /// [`TelemetryClient::flush`](crate::TelemetryClient::flush) on their own timer.
+    pub fn interval(&self) -> StdDuration {
+        self.interval
+    }
+
+    /// Returns the configured client-side sampling rate.
+    pub fn sampler(&self) -> Sampler {
+        Sampler::new(self.sample_rate)
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// Builds a [`TelemetryConfig`], exposing the `endpoint`, `interval` and `sample_rate` that
+/// [`TelemetryConfig::new`] leaves at their defaults.
+pub struct TelemetryConfigBuilder {
+    config: TelemetryConfig,
+}
+
+impl TelemetryConfigBuilder {
+    fn new(i_key: String) -> Self {
+        Self {
+            config: TelemetryConfig::new(i_key),
+        }
+    }
+
+    /// Sets the ingestion endpoint telemetry is submitted to.
+    pub fn endpoint(&mut self, endpoint: impl Into<String>) -> &mut Self {
+        self.config.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets the preferred interval between flushes, returned by
+    /// [`TelemetryConfig::interval`]. This crate doesn't flush on this interval by itself.
+    pub fn interval(&mut self, interval: StdDuration) -> &mut Self {
+        self.config.interval = interval;
+        self
+    }
+
+    /// Sets the client-side sampling rate, as a percentage kept in `[0.0, 100.0]`.
+    pub fn sample_rate(&mut self, sample_rate: f64) -> &mut Self {
+        self.config.sample_rate = sample_rate;
+        self
+    }
+
+    /// Builds the configuration.
+    pub fn build(&self) -> TelemetryConfig {
+        self.config.clone()
+    }
+}
+
+#[cfg(feature = "config-file")]
+#[derive(Debug, Default, Deserialize)]
+struct RawConfig {
+    #[serde(alias = "instrumentationKey")]
+    i_key: Option<String>,
+    endpoint: Option<String>,
+    interval: Option<String>,
+    #[serde(alias = "sampleRate")]
+    sample_rate: Option<f64>,
+}
+
+/// An error encountered while loading a [`TelemetryConfig`].
+#[cfg(feature = "config-file")]
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file could not be read.
+    Io(String, std::io::Error),
+
+    /// The config file's format is not one of `json5`, `yaml`, `toml` or `ron`.
+    UnsupportedFormat(String),
+
+    /// The config file's content could not be parsed.
+    Parse(String),
+}
+
+#[cfg(feature = "config-file")]
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "unable to read config file {}: {}", path, err),
+            ConfigError::UnsupportedFormat(ext) => write!(f, "unsupported config file format: {}", ext),
+            ConfigError::Parse(err) => write!(f, "unable to parse config file: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "config-file")]
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_defaults_to_keeping_everything() {
+        let config = TelemetryConfig::new("instrumentation".into());
+
+        assert_eq!(config.sampler().rate(), 100.0);
+    }
+
+    #[test]
+    fn it_builds_configuration_via_the_builder() {
+        let config = TelemetryConfig::builder("instrumentation".into())
+            .endpoint("https://example.com/track")
+            .interval(StdDuration::from_secs(30))
+            .sample_rate(50.0)
+            .build();
+
+        assert_eq!(config.i_key(), "instrumentation");
+        assert_eq!(config.endpoint(), "https://example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(30));
+        assert_eq!(config.sampler().rate(), 50.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_merges_raw_config_over_defaults() {
+        let config = TelemetryConfig::default().merge(RawConfig {
+            i_key: Some("instrumentation".into()),
+            endpoint: Some("https://example.com/track".into()),
+            interval: Some("30s".into()),
+            sample_rate: Some(50.0),
+        });
+
+        assert_eq!(config.i_key(), "instrumentation");
+        assert_eq!(config.endpoint(), "https://example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(30));
+        assert_eq!(config.sampler().rate(), 50.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_leaves_defaults_untouched_when_raw_config_is_empty() {
+        let config = TelemetryConfig::new("instrumentation".into()).merge(RawConfig::default());
+
+        assert_eq!(config.i_key(), "instrumentation");
+        assert_eq!(config.sampler().rate(), 100.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    struct TempFile {
+        path: std::path::PathBuf,
+    }
+
+    #[cfg(feature = "config-file")]
+    impl TempFile {
+        fn write(name: &str, content: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("appinsights-config-test-{}-{}", std::process::id(), name));
+            std::fs::write(&path, content).unwrap();
+            Self { path }
+        }
+    }
+
+    #[cfg(feature = "config-file")]
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_loads_json5_from_a_file() {
+        let file = TempFile::write(
+            "it_loads_json5_from_a_file.json5",
+            r#"{ i_key: "from-file", endpoint: "https://file.example.com/track", interval: "45s", sample_rate: 25.0 }"#,
+        );
+
+        let config = TelemetryConfig::from_file(&file.path).unwrap();
+
+        assert_eq!(config.i_key(), "from-file");
+        assert_eq!(config.endpoint(), "https://file.example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(45));
+        assert_eq!(config.sampler().rate(), 25.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_loads_yaml_from_a_file() {
+        let file = TempFile::write(
+            "it_loads_yaml_from_a_file.yaml",
+            "i_key: from-file\nendpoint: https://file.example.com/track\ninterval: 45s\nsample_rate: 25.0\n",
+        );
+
+        let config = TelemetryConfig::from_file(&file.path).unwrap();
+
+        assert_eq!(config.i_key(), "from-file");
+        assert_eq!(config.endpoint(), "https://file.example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(45));
+        assert_eq!(config.sampler().rate(), 25.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_loads_toml_from_a_file() {
+        let file = TempFile::write(
+            "it_loads_toml_from_a_file.toml",
+            "i_key = \"from-file\"\nendpoint = \"https://file.example.com/track\"\ninterval = \"45s\"\nsample_rate = 25.0\n",
+        );
+
+        let config = TelemetryConfig::from_file(&file.path).unwrap();
+
+        assert_eq!(config.i_key(), "from-file");
+        assert_eq!(config.endpoint(), "https://file.example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(45));
+        assert_eq!(config.sampler().rate(), 25.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_loads_ron_from_a_file() {
+        let file = TempFile::write(
+            "it_loads_ron_from_a_file.ron",
+            r#"(
+                i_key: Some("from-file"),
+                endpoint: Some("https://file.example.com/track"),
+                interval: Some("45s"),
+                sample_rate: Some(25.0),
+            )"#,
+        );
+
+        let config = TelemetryConfig::from_file(&file.path).unwrap();
+
+        assert_eq!(config.i_key(), "from-file");
+        assert_eq!(config.endpoint(), "https://file.example.com/track");
+        assert_eq!(config.interval(), StdDuration::from_secs(45));
+        assert_eq!(config.sampler().rate(), 25.0);
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_errors_on_an_unsupported_file_extension() {
+        let file = TempFile::write("it_errors_on_an_unsupported_file_extension.ini", "i_key=from-file");
+
+        match TelemetryConfig::from_file(&file.path) {
+            Err(ConfigError::UnsupportedFormat(ext)) => assert_eq!(ext, "ini"),
+            other => panic!("expected UnsupportedFormat, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn it_lets_the_environment_override_a_file_value_in_load() {
+        // Environment variables are process-global, so run this test's set/unset pair
+        // serially with respect to itself (it's the only test touching these vars) and
+        // always clean up, even on panic, via the guard's Drop.
+        struct EnvGuard;
+        impl Drop for EnvGuard {
+            fn drop(&mut self) {
+                std::env::remove_var(IKEY_VAR);
+            }
+        }
+
+        let file = TempFile::write(
+            "it_lets_the_environment_override_a_file_value_in_load.json5",
+            r#"{ i_key: "from-file", endpoint: "https://file.example.com/track" }"#,
+        );
+
+        std::env::set_var(IKEY_VAR, "from-env");
+        let _guard = EnvGuard;
+
+        let config = TelemetryConfig::load(Some(&file.path)).unwrap();
+
+        assert_eq!(config.i_key(), "from-env");
+        assert_eq!(config.endpoint(), "https://file.example.com/track");
+    }
+}
@@ -0,0 +1,85 @@
+//! Context shared by every telemetry item submitted through a [`TelemetryClient`](crate::TelemetryClient):
+//! the instrumentation key telemetry is submitted under, default tags/properties every item
+//! is stamped with (unless it overrides them), and the sampler used to decide whether an
+//! item is kept.
+
+use crate::telemetry::{ContextTags, Properties, Sampler};
+
+/// Normalizes an instrumentation key for use in an envelope name, e.g.
+/// `Microsoft.ApplicationInsights.<normalized>.Availability`.
+fn normalize(i_key: &str) -> String {
+    i_key.replace('-', "")
+}
+
+/// Shared context a [`TelemetryClient`](crate::TelemetryClient) attaches to every telemetry
+/// item it submits.
+pub struct TelemetryContext {
+    pub(crate) i_key: String,
+    pub(crate) normalized_i_key: String,
+    pub(crate) tags: ContextTags,
+    pub(crate) properties: Properties,
+    pub(crate) sampler: Sampler,
+}
+
+impl TelemetryContext {
+    /// Creates a context for the given instrumentation key, with default tags/properties and
+    /// a sampler that keeps everything.
+    pub fn new(i_key: String) -> Self {
+        Self {
+            normalized_i_key: normalize(&i_key),
+            i_key,
+            tags: Default::default(),
+            properties: Default::default(),
+            sampler: Default::default(),
+        }
+    }
+
+    /// Creates a context for the given instrumentation key and sampler.
+    pub(crate) fn with_sampler(i_key: String, sampler: Sampler) -> Self {
+        Self {
+            normalized_i_key: normalize(&i_key),
+            i_key,
+            tags: Default::default(),
+            properties: Default::default(),
+            sampler,
+        }
+    }
+
+    /// Returns default tags stamped on every telemetry item, unless it overrides them.
+    pub fn tags(&self) -> &ContextTags {
+        &self.tags
+    }
+
+    /// Returns a mutable reference to the default tags stamped on every telemetry item.
+    pub fn tags_mut(&mut self) -> &mut ContextTags {
+        &mut self.tags
+    }
+
+    /// Returns default properties stamped on every telemetry item, unless it overrides them.
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+
+    /// Returns a mutable reference to the default properties stamped on every telemetry item.
+    pub fn properties_mut(&mut self) -> &mut Properties {
+        &mut self.properties
+    }
+
+    /// Returns the sampler used to decide whether telemetry submitted through this context is
+    /// kept before transmission.
+    pub(crate) fn sampler(&self) -> &Sampler {
+        &self.sampler
+    }
+}
+
+impl Clone for TelemetryContext {
+    fn clone(&self) -> Self {
+        Self {
+            i_key: self.i_key.clone(),
+            normalized_i_key: self.normalized_i_key.clone(),
+            tags: self.tags.clone(),
+            properties: self.properties.clone(),
+            sampler: self.sampler,
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Wire types submitted to the Application Insights ingestion endpoint.
+
+use crate::telemetry::{Measurements, Properties};
+
+/// The outermost envelope every telemetry item is wrapped in before transmission.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope {
+    pub name: String,
+    pub time: String,
+    pub i_key: String,
+    pub sample_rate: f64,
+    pub tags: crate::telemetry::ContextTags,
+    pub data: Base,
+}
+
+/// Builds an [`Envelope`].
+pub struct EnvelopeBuilder {
+    name: String,
+    time: String,
+    i_key: String,
+    sample_rate: f64,
+    tags: crate::telemetry::ContextTags,
+    data: Option<Base>,
+}
+
+impl EnvelopeBuilder {
+    /// Starts building an envelope with the given envelope name and ISO-8601 timestamp.
+    pub fn new(name: impl Into<String>, time: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            time: time.into(),
+            i_key: String::new(),
+            sample_rate: 100.0,
+            tags: Default::default(),
+            data: None,
+        }
+    }
+
+    /// Sets the envelope's payload.
+    pub fn data(&mut self, data: Base) -> &mut Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Sets the instrumentation key telemetry is submitted under.
+    pub fn i_key(&mut self, i_key: impl Into<String>) -> &mut Self {
+        self.i_key = i_key.into();
+        self
+    }
+
+    /// Sets the client-side sampling rate stamped on the envelope.
+    pub fn sample_rate(&mut self, sample_rate: f64) -> &mut Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Sets the envelope's tags.
+    pub fn tags(&mut self, tags: crate::telemetry::ContextTags) -> &mut Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Builds the envelope.
+    pub fn build(&self) -> Envelope {
+        Envelope {
+            name: self.name.clone(),
+            time: self.time.clone(),
+            i_key: self.i_key.clone(),
+            sample_rate: self.sample_rate,
+            tags: self.tags.clone(),
+            data: self.data.clone().expect("envelope data must be set"),
+        }
+    }
+}
+
+/// Wraps an envelope's payload, today always data (as opposed to, say, a metric).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Base {
+    Data(Data),
+}
+
+/// The typed payload carried by an [`Envelope`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    AvailabilityData(AvailabilityData),
+    EventData(EventData),
+}
+
+impl Data {
+    /// Returns the envelope name Application Insights expects for this payload, scoped under
+    /// the (normalized) instrumentation key.
+    pub fn envelope_name(&self, normalized_i_key: &str) -> String {
+        match self {
+            Data::AvailabilityData(_) => format!("Microsoft.ApplicationInsights.{}.Availability", normalized_i_key),
+            Data::EventData(_) => format!("Microsoft.ApplicationInsights.{}.Event", normalized_i_key),
+        }
+    }
+}
+
+/// The result of executing an availability test, as submitted to Application Insights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailabilityData {
+    pub id: String,
+    pub name: String,
+    pub duration: String,
+    pub success: bool,
+    pub run_location: Option<String>,
+    pub message: Option<String>,
+    pub properties: Properties,
+    pub measurements: Measurements,
+}
+
+/// Builds an [`AvailabilityData`].
+pub struct AvailabilityDataBuilder {
+    id: String,
+    name: String,
+    duration: String,
+    success: bool,
+    run_location: Option<String>,
+    message: Option<String>,
+    properties: Properties,
+    measurements: Measurements,
+}
+
+impl AvailabilityDataBuilder {
+    /// Starts building an availability result with the given test run id, test name,
+    /// duration (already formatted) and success code.
+    pub fn new(id: impl Into<String>, name: impl Into<String>, duration: impl Into<String>, success: bool) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            duration: duration.into(),
+            success,
+            run_location: None,
+            message: None,
+            properties: Default::default(),
+            measurements: Default::default(),
+        }
+    }
+
+    /// Sets the custom properties to submit with the result.
+    pub fn properties(&mut self, properties: Properties) -> &mut Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Sets the custom measurements to submit with the result.
+    pub fn measurements(&mut self, measurements: Measurements) -> &mut Self {
+        self.measurements = measurements;
+        self
+    }
+
+    /// Sets the name of the location where the test was run.
+    pub fn run_location(&mut self, run_location: impl Into<String>) -> &mut Self {
+        self.run_location = Some(run_location.into());
+        self
+    }
+
+    /// Sets the diagnostic message for the result.
+    pub fn message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Builds the availability result.
+    pub fn build(&self) -> AvailabilityData {
+        AvailabilityData {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            duration: self.duration.clone(),
+            success: self.success,
+            run_location: self.run_location.clone(),
+            message: self.message.clone(),
+            properties: self.properties.clone(),
+            measurements: self.measurements.clone(),
+        }
+    }
+}
+
+/// A custom event, as submitted to Application Insights. Emitted for telemetry types
+/// generated by `appinsights-contracts-codegen` from a declarative event schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventData {
+    pub name: String,
+    pub properties: Properties,
+    pub measurements: Measurements,
+}
+
+/// Builds an [`EventData`].
+pub struct EventDataBuilder {
+    name: String,
+    properties: Properties,
+    measurements: Measurements,
+}
+
+impl EventDataBuilder {
+    /// Starts building a custom event named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            properties: Default::default(),
+            measurements: Default::default(),
+        }
+    }
+
+    /// Sets the custom properties to submit with the event.
+    pub fn properties(&mut self, properties: Properties) -> &mut Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Sets the custom measurements to submit with the event.
+    pub fn measurements(&mut self, measurements: Measurements) -> &mut Self {
+        self.measurements = measurements;
+        self
+    }
+
+    /// Builds the custom event.
+    pub fn build(&self) -> EventData {
+        EventData {
+            name: self.name.clone(),
+            properties: self.properties.clone(),
+            measurements: self.measurements.clone(),
+        }
+    }
+}
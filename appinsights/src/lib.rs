@@ -0,0 +1,19 @@
+//! Application Insights SDK for Rust.
+
+mod channel;
+pub mod config;
+pub mod context;
+pub mod contracts;
+mod client;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+pub mod telemetry;
+pub mod time;
+#[cfg(feature = "tracing-integration")]
+mod tracing;
+mod uuid;
+
+pub use client::TelemetryClient;
+pub use config::TelemetryConfig;
+#[cfg(feature = "tracing-integration")]
+pub use tracing::AppInsightsLayer;
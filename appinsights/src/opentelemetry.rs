@@ -0,0 +1,159 @@
+//! Converts this crate's telemetry into [OpenTelemetry](https://opentelemetry.io) data, so
+//! applications that already run an OTel pipeline (OTLP, Datadog, Jaeger, stdout, ...) can
+//! emit Application Insights telemetry through it instead of talking to the ingestion
+//! endpoint directly.
+//!
+//! Enabled with the `opentelemetry` feature.
+
+use std::time::SystemTime;
+
+use opentelemetry::trace::{SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId, TraceState};
+use opentelemetry::{InstrumentationScope, KeyValue};
+use opentelemetry_sdk::trace::SpanData;
+
+use crate::telemetry::{AvailabilityTelemetry, Telemetry};
+
+impl From<AvailabilityTelemetry> for SpanData {
+    /// Maps an availability test result onto an OTel span: `name` becomes the span name,
+    /// `duration` is derived from the telemetry's end timestamp and elapsed duration,
+    /// `success` becomes the span status, and `id`/`run_location`/`message` are carried
+    /// over as span attributes alongside its `properties`/`tags`. `measurements` are also
+    /// carried over as span attributes (prefixed `ai.measurement.`), not as OTel metric
+    /// instruments — this module doesn't produce the metrics signal.
+    fn from(telemetry: AvailabilityTelemetry) -> Self {
+        let end_time: SystemTime = telemetry.timestamp().into();
+        let start_time = end_time - telemetry.duration().into();
+
+        let mut attributes: Vec<KeyValue> = telemetry
+            .properties()
+            .iter()
+            .chain(telemetry.tags().iter())
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect();
+
+        attributes.extend(
+            telemetry
+                .measurements()
+                .iter()
+                .map(|(name, value)| KeyValue::new(format!("ai.measurement.{}", name), *value)),
+        );
+
+        if let Some(id) = telemetry.id() {
+            attributes.push(KeyValue::new("ai.availability.id", id.to_hyphenated().to_string()));
+        }
+        if let Some(run_location) = telemetry.run_location() {
+            attributes.push(KeyValue::new("ai.availability.run_location", run_location.to_string()));
+        }
+        if let Some(message) = telemetry.message() {
+            attributes.push(KeyValue::new("ai.availability.message", message.to_string()));
+        }
+
+        let status = if telemetry.success() {
+            Status::Ok
+        } else {
+            Status::error("")
+        };
+
+        SpanData {
+            span_context: SpanContext::new(
+                TraceId::INVALID,
+                SpanId::INVALID,
+                TraceFlags::default(),
+                false,
+                TraceState::default(),
+            ),
+            parent_span_id: SpanId::INVALID,
+            parent_span_is_remote: false,
+            span_kind: SpanKind::Client,
+            name: telemetry.name().to_string().into(),
+            start_time,
+            end_time,
+            attributes,
+            dropped_attributes_count: 0,
+            events: Default::default(),
+            links: Default::default(),
+            status,
+            instrumentation_scope: InstrumentationScope::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use opentelemetry::Key;
+
+    use super::*;
+    use crate::uuid::Uuid;
+
+    fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a opentelemetry::Value> {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key == Key::new(key.to_string()))
+            .map(|kv| &kv.value)
+    }
+
+    #[test]
+    fn it_derives_span_start_time_from_the_end_timestamp_and_duration() {
+        let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(2), true);
+        let end_time: SystemTime = telemetry.timestamp().into();
+
+        let span: SpanData = telemetry.into();
+
+        assert_eq!(span.end_time, end_time);
+        assert_eq!(span.start_time, end_time - StdDuration::from_secs(2));
+    }
+
+    #[test]
+    fn it_maps_success_to_an_ok_status_and_failure_to_an_error_status() {
+        let success: SpanData = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), true).into();
+        assert_eq!(success.status, Status::Ok);
+
+        let failure: SpanData = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), false).into();
+        assert_ne!(failure.status, Status::Ok);
+    }
+
+    #[test]
+    fn it_carries_id_run_location_and_message_as_attributes() {
+        let id = Uuid::new_v4();
+        let mut builder = AvailabilityTelemetry::builder("probe".into(), StdDuration::from_secs(1), true);
+        builder.id(id).run_location("us-west-2").message("all good");
+
+        let span: SpanData = builder.build().into();
+
+        assert_eq!(
+            attribute(&span, "ai.availability.id"),
+            Some(&opentelemetry::Value::from(id.to_hyphenated().to_string()))
+        );
+        assert_eq!(
+            attribute(&span, "ai.availability.run_location"),
+            Some(&opentelemetry::Value::from("us-west-2"))
+        );
+        assert_eq!(
+            attribute(&span, "ai.availability.message"),
+            Some(&opentelemetry::Value::from("all good"))
+        );
+    }
+
+    #[test]
+    fn it_carries_measurements_as_prefixed_attributes_not_metric_instruments() {
+        let mut builder = AvailabilityTelemetry::builder("probe".into(), StdDuration::from_secs(1), true);
+        builder.measurement("latency_ms", 42.0);
+
+        let span: SpanData = builder.build().into();
+
+        assert_eq!(attribute(&span, "ai.measurement.latency_ms"), Some(&opentelemetry::Value::from(42.0)));
+    }
+
+    #[test]
+    fn it_carries_properties_and_tags_as_attributes() {
+        let mut builder = AvailabilityTelemetry::builder("probe".into(), StdDuration::from_secs(1), true);
+        builder.property("region", "us-west-2").tag("ai.operation.id", "abc123");
+
+        let span: SpanData = builder.build().into();
+
+        assert_eq!(attribute(&span, "region"), Some(&opentelemetry::Value::from("us-west-2")));
+        assert_eq!(attribute(&span, "ai.operation.id"), Some(&opentelemetry::Value::from("abc123")));
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::VecDeque;
+use std::time::{Duration as StdDuration, Instant};
+
+use crate::telemetry::Telemetry;
+
+/// A single sample fed into an [`AlertRule`]'s rolling window: either a named measurement
+/// value, or an availability success/failure flag.
+#[derive(Debug, Clone)]
+pub enum Sample {
+    /// The value of a named measurement, e.g. `latency`.
+    Measurement { name: String, value: f64 },
+
+    /// Whether an availability telemetry item succeeded.
+    Success(bool),
+}
+
+/// What an [`AlertRule`] watches and how its rolling aggregate is compared against a
+/// threshold.
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    /// Fires when the success rate over the window drops below `rate` percent.
+    SuccessRateBelow(f64),
+
+    /// Fires when the average of measurement `name` over the window exceeds `bound`.
+    MeasurementAbove { name: String, bound: f64 },
+}
+
+/// A rule that watches aggregated telemetry over a rolling `window` and invokes an
+/// [`AlertMethod`] when `predicate` is crossed.
+pub struct AlertRule {
+    name: String,
+    window: StdDuration,
+    predicate: Predicate,
+    method: Box<dyn AlertMethod>,
+    samples: VecDeque<(Instant, Sample)>,
+}
+
+impl AlertRule {
+    /// Creates a rule named `name` that evaluates `predicate` over a rolling `window` and
+    /// invokes `method` whenever it is crossed.
+    pub fn new(
+        name: impl Into<String>,
+        window: StdDuration,
+        predicate: Predicate,
+        method: impl AlertMethod + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            window,
+            predicate,
+            method: Box::new(method),
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Records telemetry for this rule's rolling aggregate. Cheap: just appends to the
+    /// window and evicts expired samples. The recorded window is compared against
+    /// `predicate` separately, in [`AlertRule::evaluate`], which
+    /// [`TelemetryChannel::flush`](crate::channel::TelemetryChannel::flush) calls, so the
+    /// per-submit ingestion path never pays for evaluating the predicate.
+    pub fn observe(&mut self, telemetry: &impl Telemetry) {
+        let now = Instant::now();
+
+        if let Some(success) = telemetry.is_successful() {
+            self.samples.push_back((now, Sample::Success(success)));
+        }
+        for (name, value) in telemetry.measurements().iter() {
+            self.samples.push_back((
+                now,
+                Sample::Measurement {
+                    name: name.clone(),
+                    value: *value,
+                },
+            ));
+        }
+
+        self.evict_expired(now);
+    }
+
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((recorded_at, _)) = self.samples.front() {
+            if now.duration_since(*recorded_at) > self.window {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Evicts samples that have fallen out of the window, then compares what's left against
+    /// `predicate` and invokes `method` if it's crossed. Called on flush, not from
+    /// [`AlertRule::observe`], so evaluating the predicate never slows down telemetry
+    /// submission. Evicting here too (rather than only in `observe`) means a rule whose
+    /// telemetry stops flowing reports "no recent data" instead of scoring stale samples
+    /// forever.
+    pub(crate) fn evaluate(&mut self) {
+        self.evict_expired(Instant::now());
+
+        let observed = match &self.predicate {
+            Predicate::SuccessRateBelow(_) => self.success_rate(),
+            Predicate::MeasurementAbove { name, .. } => self.measurement_average(name),
+        };
+
+        let (observed, threshold, crossed) = match (&self.predicate, observed) {
+            (Predicate::SuccessRateBelow(rate), Some(observed)) => (observed, *rate, observed < *rate),
+            (Predicate::MeasurementAbove { bound, .. }, Some(observed)) => (observed, *bound, observed > *bound),
+            (_, None) => return,
+        };
+
+        if crossed {
+            self.method.alert(&self.name, observed, threshold);
+        }
+    }
+
+    fn success_rate(&self) -> Option<f64> {
+        let mut total = 0usize;
+        let mut successes = 0usize;
+
+        for (_, sample) in &self.samples {
+            if let Sample::Success(success) = sample {
+                total += 1;
+                if *success {
+                    successes += 1;
+                }
+            }
+        }
+
+        if total == 0 {
+            None
+        } else {
+            Some((successes as f64 / total as f64) * 100.0)
+        }
+    }
+
+    fn measurement_average(&self, name: &str) -> Option<f64> {
+        let values: Vec<f64> = self
+            .samples
+            .iter()
+            .filter_map(|(_, sample)| match sample {
+                Sample::Measurement { name: sample_name, value } if sample_name == name => Some(*value),
+                _ => None,
+            })
+            .collect();
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    }
+}
+
+/// An action invoked when an [`AlertRule`]'s predicate is crossed.
+pub trait AlertMethod: Send + Sync {
+    /// Called with the rule's `name`, the `observed` aggregate value and the configured
+    /// `threshold` it crossed.
+    fn alert(&self, name: &str, observed: f64, threshold: f64);
+}
+
+/// Logs a message built from a small template of alert tokens (`{name}`, `{observed}`,
+/// `{threshold}`) to stderr.
+pub struct LogAlert {
+    template: String,
+}
+
+impl LogAlert {
+    /// Creates a method using the default `"alert {name}: observed {observed}, threshold
+    /// {threshold}"` template.
+    pub fn new() -> Self {
+        Self {
+            template: "alert {name}: observed {observed}, threshold {threshold}".into(),
+        }
+    }
+
+    /// Creates a method using a custom template.
+    pub fn with_template(template: impl Into<String>) -> Self {
+        Self { template: template.into() }
+    }
+}
+
+impl Default for LogAlert {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertMethod for LogAlert {
+    fn alert(&self, name: &str, observed: f64, threshold: f64) {
+        let message = self
+            .template
+            .replace("{name}", name)
+            .replace("{observed}", &observed.to_string())
+            .replace("{threshold}", &threshold.to_string());
+
+        eprintln!("{}", message);
+    }
+}
+
+/// Invokes a user-supplied callback.
+pub struct CallbackAlert<F>(F);
+
+impl<F> CallbackAlert<F>
+where
+    F: Fn(&str, f64, f64) + Send + Sync,
+{
+    /// Creates a method that invokes `callback` with the rule name, observed value and
+    /// threshold.
+    pub fn new(callback: F) -> Self {
+        Self(callback)
+    }
+}
+
+impl<F> AlertMethod for CallbackAlert<F>
+where
+    F: Fn(&str, f64, f64) + Send + Sync,
+{
+    fn alert(&self, name: &str, observed: f64, threshold: f64) {
+        (self.0)(name, observed, threshold)
+    }
+}
+
+/// Delivers a rendered alert message somewhere: a webhook, an email gateway, a chat
+/// integration, etc. Kept generic over the delivery mechanism so this crate doesn't need to
+/// pick (and depend on) an HTTP client on the caller's behalf.
+pub trait Transport: Send + Sync {
+    /// Sends `message` (already rendered from the template) and reports delivery failures to
+    /// stderr, since an [`AlertMethod`] has no caller to propagate an error to.
+    fn send(&self, message: &str);
+}
+
+/// Renders a message from a template (`{name}`, `{observed}`, `{threshold}`) and delivers it
+/// through a [`Transport`], for webhook and email alert integrations.
+pub struct WebhookAlert<T> {
+    template: String,
+    transport: T,
+}
+
+impl<T> WebhookAlert<T>
+where
+    T: Transport,
+{
+    /// Creates a method using the default `"alert {name}: observed {observed}, threshold
+    /// {threshold}"` template, delivered through `transport`.
+    pub fn new(transport: T) -> Self {
+        Self {
+            template: "alert {name}: observed {observed}, threshold {threshold}".into(),
+            transport,
+        }
+    }
+
+    /// Creates a method using a custom template, delivered through `transport`.
+    pub fn with_template(template: impl Into<String>, transport: T) -> Self {
+        Self {
+            template: template.into(),
+            transport,
+        }
+    }
+}
+
+impl<T> AlertMethod for WebhookAlert<T>
+where
+    T: Send + Sync,
+    T: Transport,
+{
+    fn alert(&self, name: &str, observed: f64, threshold: f64) {
+        let message = self
+            .template
+            .replace("{name}", name)
+            .replace("{observed}", &observed.to_string())
+            .replace("{threshold}", &threshold.to_string());
+
+        self.transport.send(&message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::telemetry::AvailabilityTelemetry;
+
+    #[test]
+    fn it_fires_when_success_rate_drops_below_threshold() {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_handle = fired.clone();
+
+        let mut rule = AlertRule::new(
+            "availability",
+            StdDuration::from_secs(60),
+            Predicate::SuccessRateBelow(90.0),
+            CallbackAlert::new(move |_, _, _| {
+                fired_handle.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+
+        for success in [true, true, false, false] {
+            let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), success);
+            rule.observe(&telemetry);
+        }
+        rule.evaluate();
+
+        assert!(fired.load(Ordering::SeqCst) > 0);
+    }
+
+    #[test]
+    fn it_evicts_stale_samples_on_evaluate_even_without_a_new_observe() {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_handle = fired.clone();
+
+        let mut rule = AlertRule::new(
+            "availability",
+            StdDuration::from_millis(10),
+            Predicate::SuccessRateBelow(90.0),
+            CallbackAlert::new(move |_, _, _| {
+                fired_handle.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+
+        let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), false);
+        rule.observe(&telemetry);
+
+        std::thread::sleep(StdDuration::from_millis(20));
+
+        // No new telemetry flowed in since the window expired, so evaluate() alone should
+        // evict the stale sample and find nothing to score, rather than alerting forever on
+        // data that's no longer recent.
+        rule.evaluate();
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn it_does_not_fire_while_above_threshold() {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_handle = fired.clone();
+
+        let mut rule = AlertRule::new(
+            "availability",
+            StdDuration::from_secs(60),
+            Predicate::SuccessRateBelow(50.0),
+            CallbackAlert::new(move |_, _, _| {
+                fired_handle.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+
+        for _ in 0..4 {
+            let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), true);
+            rule.observe(&telemetry);
+        }
+        rule.evaluate();
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn it_delivers_webhook_alerts_through_the_configured_transport() {
+        struct RecordingTransport {
+            sent: Arc<std::sync::Mutex<Vec<String>>>,
+        }
+
+        impl Transport for RecordingTransport {
+            fn send(&self, message: &str) {
+                self.sent.lock().unwrap().push(message.to_string());
+            }
+        }
+
+        let sent = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut rule = AlertRule::new(
+            "availability",
+            StdDuration::from_secs(60),
+            Predicate::SuccessRateBelow(90.0),
+            WebhookAlert::new(RecordingTransport { sent: sent.clone() }),
+        );
+
+        for success in [true, false] {
+            let telemetry = AvailabilityTelemetry::new("probe".into(), StdDuration::from_secs(1), success);
+            rule.observe(&telemetry);
+        }
+        rule.evaluate();
+
+        assert_eq!(sent.lock().unwrap().len(), 1);
+        assert!(sent.lock().unwrap()[0].contains("availability"));
+    }
+}
@@ -4,7 +4,7 @@ use chrono::{DateTime, SecondsFormat, Utc};
 
 use crate::context::TelemetryContext;
 use crate::contracts::*;
-use crate::telemetry::{ContextTags, Measurements, Properties, Telemetry};
+use crate::telemetry::{sampler, Combine, ContextTags, Measurements, Properties, Telemetry};
 use crate::time::{self, Duration};
 use crate::uuid::Uuid;
 
@@ -59,14 +59,138 @@ impl AvailabilityTelemetry {
         }
     }
 
-    /// Returns custom measurements to submit with the telemetry item.
-    pub fn measurements(&self) -> &Measurements {
-        &self.measurements
+    /// Starts building an availability telemetry item with the specified test name, duration
+    /// and success code, additionally exposing the test run `id`, `run_location` and
+    /// diagnostic `message` that [`AvailabilityTelemetry::new`] leaves at their defaults.
+    pub fn builder(name: String, duration: StdDuration, success: bool) -> AvailabilityTelemetryBuilder {
+        AvailabilityTelemetryBuilder::new(name, duration, success)
     }
 
-    /// Returns mutable reference to custom measurements.
-    pub fn measurements_mut(&mut self) -> &mut Measurements {
-        &mut self.measurements
+    /// Returns the identifier of this test run, if one was set.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn id(&self) -> Option<&Uuid> {
+        self.id.as_ref()
+    }
+
+    /// Returns the name of the test that this result represents.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the duration of the test run.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns whether the test run succeeded.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the location where the test was run, if one was set.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn run_location(&self) -> Option<&str> {
+        self.run_location.as_deref()
+    }
+
+    /// Returns the diagnostic message for the result, if one was set.
+    #[cfg(feature = "opentelemetry")]
+    pub(crate) fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+/// Builds an [`AvailabilityTelemetry`], additionally exposing its `id`, `run_location` and
+/// `message` fields that [`AvailabilityTelemetry::new`] leaves unreachable from outside the
+/// crate.
+pub struct AvailabilityTelemetryBuilder {
+    id: Option<Uuid>,
+    name: String,
+    duration: Duration,
+    success: bool,
+    timestamp: DateTime<Utc>,
+    run_location: Option<String>,
+    message: Option<String>,
+    properties: Properties,
+    tags: ContextTags,
+    measurements: Measurements,
+}
+
+impl AvailabilityTelemetryBuilder {
+    fn new(name: String, duration: StdDuration, success: bool) -> Self {
+        Self {
+            id: Default::default(),
+            name,
+            duration: duration.into(),
+            success,
+            timestamp: time::now(),
+            run_location: Default::default(),
+            message: Default::default(),
+            properties: Default::default(),
+            tags: Default::default(),
+            measurements: Default::default(),
+        }
+    }
+
+    /// Sets the identifier that correlates this result with other steps of the same test run.
+    pub fn id(&mut self, id: Uuid) -> &mut Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the location where the test was run.
+    pub fn run_location(&mut self, run_location: impl Into<String>) -> &mut Self {
+        self.run_location = Some(run_location.into());
+        self
+    }
+
+    /// Sets a diagnostic message for the result.
+    pub fn message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets the time stamp when this telemetry was measured, overriding the default of now.
+    pub fn timestamp(&mut self, timestamp: DateTime<Utc>) -> &mut Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Inserts a custom property to submit with the telemetry item.
+    pub fn property(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Inserts a custom measurement to submit with the telemetry item.
+    pub fn measurement(&mut self, key: impl Into<String>, value: f64) -> &mut Self {
+        self.measurements.insert(key.into(), value);
+        self
+    }
+
+    /// Inserts a tag that overrides the value found on the client telemetry context.
+    pub fn tag(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Builds the availability telemetry item.
+    pub fn build(&self) -> AvailabilityTelemetry {
+        AvailabilityTelemetry {
+            id: self.id,
+            name: self.name.clone(),
+            duration: self.duration,
+            success: self.success,
+            timestamp: self.timestamp,
+            run_location: self.run_location.clone(),
+            message: self.message.clone(),
+            properties: self.properties.clone(),
+            tags: self.tags.clone(),
+            measurements: self.measurements.clone(),
+        }
     }
 }
 
@@ -95,6 +219,28 @@ impl Telemetry for AvailabilityTelemetry {
     fn tags_mut(&mut self) -> &mut ContextTags {
         &mut self.tags
     }
+
+    /// Returns custom measurements to submit with the telemetry item.
+    fn measurements(&self) -> &Measurements {
+        &self.measurements
+    }
+
+    /// Returns mutable reference to custom measurements.
+    fn measurements_mut(&mut self) -> &mut Measurements {
+        &mut self.measurements
+    }
+
+    /// Returns the key used to make a stable sampling decision for this item: the operation
+    /// id tag when present, otherwise this item's own test run `id`, otherwise a random
+    /// value.
+    fn sampling_key(&self) -> String {
+        sampler::sampling_key(&self.tags, self.id.as_ref())
+    }
+
+    /// Returns whether the test run succeeded.
+    fn is_successful(&self) -> Option<bool> {
+        Some(self.success)
+    }
 }
 
 impl From<(TelemetryContext, AvailabilityTelemetry)> for Envelope {
@@ -115,7 +261,7 @@ impl From<(TelemetryContext, AvailabilityTelemetry)> for Envelope {
             }
 
             if let Some(message) = telemetry.message {
-                builder.run_location(message);
+                builder.message(message);
             }
 
             builder.build()
@@ -123,10 +269,12 @@ impl From<(TelemetryContext, AvailabilityTelemetry)> for Envelope {
 
         let envelope_name = data.envelope_name(&context.normalized_i_key);
         let timestamp = telemetry.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true);
+        let sample_rate = context.sampler.rate();
 
         EnvelopeBuilder::new(envelope_name, timestamp)
             .data(Base::Data(data))
             .i_key(context.i_key)
+            .sample_rate(sample_rate)
             .tags(ContextTags::combine(context.tags, telemetry.tags))
             .build()
     }
@@ -142,7 +290,7 @@ mod tests {
 
     #[test]
     fn it_overrides_properties_from_context() {
-        time::set(Utc.ymd(2019, 1, 2).and_hms_milli(3, 4, 5, 800));
+        time::set(Utc.with_ymd_and_hms(2019, 1, 2, 3, 4, 5).unwrap() + chrono::Duration::milliseconds(800));
 
         let mut context = TelemetryContext::new("instrumentation".into());
         context.properties_mut().insert("test".into(), "ok".into());
@@ -178,6 +326,7 @@ mod tests {
                 .build(),
         )))
         .i_key("instrumentation")
+        .sample_rate(100.0)
         .tags(BTreeMap::default())
         .build();
 
@@ -186,7 +335,7 @@ mod tests {
 
     #[test]
     fn it_overrides_tags_from_context() {
-        time::set(Utc.ymd(2019, 1, 2).and_hms_milli(3, 4, 5, 700));
+        time::set(Utc.with_ymd_and_hms(2019, 1, 2, 3, 4, 5).unwrap() + chrono::Duration::milliseconds(700));
 
         let mut context = TelemetryContext::new("instrumentation".into());
         context.tags_mut().insert("test".into(), "ok".into());
@@ -217,6 +366,7 @@ mod tests {
                 .build(),
         )))
         .i_key("instrumentation")
+        .sample_rate(100.0)
         .tags({
             let mut tags = BTreeMap::default();
             tags.insert("test".into(), "ok".into());
@@ -227,4 +377,48 @@ mod tests {
 
         assert_eq!(envelop, expected)
     }
+
+    #[test]
+    fn it_builds_full_availability_telemetry_with_id_run_location_and_message() {
+        time::set(Utc.with_ymd_and_hms(2019, 1, 2, 3, 4, 5).unwrap() + chrono::Duration::milliseconds(900));
+
+        let context = TelemetryContext::new("instrumentation".into());
+
+        let id = Uuid::new_v4();
+        let telemetry = AvailabilityTelemetry::builder(
+            "GET https://example.com/main.html".into(),
+            StdDuration::from_secs(2),
+            true,
+        )
+        .id(id)
+        .run_location("us-west-2")
+        .message("did not find expected text")
+        .build();
+
+        let envelop = Envelope::from((context, telemetry));
+
+        let expected = EnvelopeBuilder::new(
+            "Microsoft.ApplicationInsights.instrumentation.Availability",
+            "2019-01-02T03:04:05.900Z",
+        )
+        .data(Base::Data(Data::AvailabilityData(
+            AvailabilityDataBuilder::new(
+                id.to_hyphenated().to_string(),
+                "GET https://example.com/main.html",
+                "0.00:00:02.0000000",
+                true,
+            )
+            .properties(Properties::default())
+            .measurements(Measurements::default())
+            .run_location("us-west-2")
+            .message("did not find expected text")
+            .build(),
+        )))
+        .i_key("instrumentation")
+        .sample_rate(100.0)
+        .tags(BTreeMap::default())
+        .build();
+
+        assert_eq!(envelop, expected)
+    }
 }
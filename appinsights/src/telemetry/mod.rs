@@ -0,0 +1,77 @@
+//! Telemetry types submitted through a [`TelemetryClient`](crate::TelemetryClient), and the
+//! shared [`Telemetry`] trait they all implement.
+
+mod alert;
+mod availability;
+pub(crate) mod sampler;
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+pub use alert::{AlertMethod, AlertRule, CallbackAlert, LogAlert, Predicate, Transport, WebhookAlert};
+pub use availability::{AvailabilityTelemetry, AvailabilityTelemetryBuilder};
+pub(crate) use sampler::Sampler;
+
+/// Custom properties attached to a telemetry item.
+pub type Properties = BTreeMap<String, String>;
+
+/// Custom measurements attached to a telemetry item.
+pub type Measurements = BTreeMap<String, f64>;
+
+/// Tags that override values found on the client's [`TelemetryContext`](crate::context::TelemetryContext).
+pub type ContextTags = BTreeMap<String, String>;
+
+/// Combines a client's default values with a telemetry item's own, letting the item's own
+/// values win on key collisions.
+pub trait Combine {
+    fn combine(context: Self, telemetry: Self) -> Self;
+}
+
+impl<V> Combine for BTreeMap<String, V> {
+    fn combine(mut context: Self, telemetry: Self) -> Self {
+        context.extend(telemetry);
+        context
+    }
+}
+
+/// Common interface of every telemetry item a [`TelemetryClient`](crate::TelemetryClient) can
+/// submit.
+pub trait Telemetry {
+    /// Returns the time when this telemetry was measured.
+    fn timestamp(&self) -> DateTime<Utc>;
+
+    /// Returns custom properties to submit with the telemetry item.
+    fn properties(&self) -> &Properties;
+
+    /// Returns mutable reference to custom properties.
+    fn properties_mut(&mut self) -> &mut Properties;
+
+    /// Returns context data containing extra, optional tags. Overrides values found on client telemetry context.
+    fn tags(&self) -> &ContextTags;
+
+    /// Returns mutable reference to custom tags.
+    fn tags_mut(&mut self) -> &mut ContextTags;
+
+    /// Returns custom measurements to submit with the telemetry item.
+    fn measurements(&self) -> &Measurements;
+
+    /// Returns mutable reference to custom measurements.
+    fn measurements_mut(&mut self) -> &mut Measurements;
+
+    /// Returns the key used to make a stable sampling decision for this item, so that every
+    /// telemetry item generated for the same operation is sampled in or out together.
+    ///
+    /// Defaults to the operation id tag, falling back to a random value; override when a
+    /// more specific identifier (e.g. the item's own id) is available.
+    fn sampling_key(&self) -> String {
+        sampler::sampling_key(self.tags(), None)
+    }
+
+    /// Returns whether this telemetry item represents a successful operation, if applicable.
+    /// Used by [`AlertRule`] to evaluate success-rate predicates; `None` for telemetry types
+    /// that don't carry a success/failure outcome.
+    fn is_successful(&self) -> Option<bool> {
+        None
+    }
+}
@@ -0,0 +1,128 @@
+use crate::telemetry::ContextTags;
+use crate::uuid::Uuid;
+
+/// Decides whether a telemetry item is kept or dropped before transmission, so that
+/// high-volume applications can cut ingestion cost while the backend scales the
+/// surviving counts back up using the stamped sample rate.
+///
+/// Sampling is deterministic: the same sampling key always produces the same
+/// decision, so every telemetry item generated for one logical operation (a request
+/// and the dependencies/traces it spawns) is kept or dropped together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sampler {
+    rate: f64,
+}
+
+impl Sampler {
+    /// Creates a sampler that keeps telemetry with probability `rate` percent.
+    ///
+    /// `rate` is clamped to `[0.0, 100.0]`.
+    pub fn new(rate: f64) -> Self {
+        Self {
+            rate: rate.clamp(0.0, 100.0),
+        }
+    }
+
+    /// Returns the configured sampling rate.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Determines whether the item identified by `key` should be kept and, if so,
+    /// returns the sample rate to stamp on its `Envelope`.
+    pub fn sample(&self, key: &str) -> Option<f64> {
+        if score(key) < self.rate {
+            Some(self.rate)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Sampler {
+    /// Keeps every telemetry item, which preserves today's behavior unless configured.
+    fn default() -> Self {
+        Self { rate: 100.0 }
+    }
+}
+
+/// Derives a stable sampling key for a telemetry item: the operation id from `tags`
+/// when present, otherwise the telemetry's own `id`, otherwise a random value.
+pub fn sampling_key(tags: &ContextTags, id: Option<&Uuid>) -> String {
+    tags.get("ai.operation.id")
+        .cloned()
+        .or_else(|| id.map(|id| id.to_hyphenated().to_string()))
+        .unwrap_or_else(|| Uuid::new_v4().to_hyphenated().to_string())
+}
+
+/// Computes a stable score in `[0.0, 100.0)` for `key` from a 32-bit FNV-1a hash,
+/// so the same key always normalizes to the same point in the sampling range.
+fn score(key: &str) -> f64 {
+    (fnv1a(key) as f64 / (u32::MAX as f64 + 1.0)) * 100.0
+}
+
+fn fnv1a(key: &str) -> u32 {
+    const OFFSET_BASIS: u32 = 0x811c_9dc5;
+    const PRIME: u32 = 0x0100_0193;
+
+    key.bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u32).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_keeps_everything_by_default() {
+        let sampler = Sampler::default();
+
+        assert_eq!(sampler.sample("any-key"), Some(100.0));
+    }
+
+    #[test]
+    fn it_drops_everything_at_zero_rate() {
+        let sampler = Sampler::new(0.0);
+
+        assert_eq!(sampler.sample("any-key"), None);
+    }
+
+    #[test]
+    fn it_is_deterministic_for_the_same_key() {
+        let sampler = Sampler::new(50.0);
+
+        assert_eq!(sampler.sample("operation-1"), sampler.sample("operation-1"));
+    }
+
+    #[test]
+    fn it_stamps_the_configured_rate_on_kept_items() {
+        let sampler = Sampler::new(100.0);
+
+        assert_eq!(sampler.sample("operation-1"), Some(100.0));
+    }
+
+    #[test]
+    fn it_prefers_the_operation_id_tag_over_the_telemetry_id() {
+        let mut tags = ContextTags::default();
+        tags.insert("ai.operation.id".into(), "op-42".into());
+
+        let id = Uuid::new_v4();
+
+        assert_eq!(sampling_key(&tags, Some(&id)), "op-42");
+    }
+
+    #[test]
+    fn it_falls_back_to_the_telemetry_id() {
+        let tags = ContextTags::default();
+        let id = Uuid::new_v4();
+
+        assert_eq!(sampling_key(&tags, Some(&id)), id.to_hyphenated().to_string());
+    }
+
+    #[test]
+    fn it_falls_back_to_a_random_value_when_nothing_else_is_available() {
+        let tags = ContextTags::default();
+
+        assert_ne!(sampling_key(&tags, None), sampling_key(&tags, None));
+    }
+}
@@ -0,0 +1,66 @@
+//! The current time, indirected so tests can pin it to a fixed instant, plus a `Duration`
+//! that renders in the `d.hh:mm:ss.fffffff` format Application Insights expects.
+
+use std::cell::Cell;
+use std::fmt;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+
+const TICKS_PER_SECOND: i64 = 10_000_000;
+const TICKS_PER_MINUTE: i64 = TICKS_PER_SECOND * 60;
+const TICKS_PER_HOUR: i64 = TICKS_PER_MINUTE * 60;
+const TICKS_PER_DAY: i64 = TICKS_PER_HOUR * 24;
+
+thread_local! {
+    static OVERRIDE: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
+
+/// Returns the current time, or the time pinned by [`set`] when running under test.
+pub fn now() -> DateTime<Utc> {
+    OVERRIDE.with(|cell| cell.get()).unwrap_or_else(Utc::now)
+}
+
+/// Pins [`now`] to a fixed instant for the current thread, for use in tests.
+#[cfg(test)]
+pub(crate) fn set(time: DateTime<Utc>) {
+    OVERRIDE.with(|cell| cell.set(Some(time)));
+}
+
+/// A span of time, measured in 100-nanosecond ticks like a .NET `TimeSpan`, which is how
+/// Application Insights represents durations on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration {
+    ticks: i64,
+}
+
+impl From<StdDuration> for Duration {
+    fn from(duration: StdDuration) -> Self {
+        let ticks = duration.as_secs() as i64 * TICKS_PER_SECOND + duration.subsec_nanos() as i64 / 100;
+        Self { ticks }
+    }
+}
+
+impl From<Duration> for StdDuration {
+    fn from(duration: Duration) -> Self {
+        let ticks = duration.ticks.max(0) as u64;
+        let secs = ticks / TICKS_PER_SECOND as u64;
+        let nanos = (ticks % TICKS_PER_SECOND as u64) * 100;
+        StdDuration::new(secs, nanos as u32)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.ticks / TICKS_PER_DAY;
+        let remainder = self.ticks % TICKS_PER_DAY;
+        let hours = remainder / TICKS_PER_HOUR;
+        let remainder = remainder % TICKS_PER_HOUR;
+        let minutes = remainder / TICKS_PER_MINUTE;
+        let remainder = remainder % TICKS_PER_MINUTE;
+        let seconds = remainder / TICKS_PER_SECOND;
+        let fraction = remainder % TICKS_PER_SECOND;
+
+        write!(f, "{}.{:02}:{:02}:{:02}.{:07}", days, hours, minutes, seconds, fraction)
+    }
+}
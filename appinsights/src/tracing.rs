@@ -0,0 +1,267 @@
+//! A [`tracing_subscriber::Layer`] that turns closed `tracing` spans into telemetry, so
+//! instrumented code produces telemetry automatically without manual `TelemetryClient` calls.
+//!
+//! Enabled with the `tracing-integration` feature.
+
+use std::time::Duration as StdDuration;
+
+use tracing::span::Attributes;
+use tracing::{Id, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::telemetry::{AvailabilityTelemetry, Telemetry};
+use crate::time;
+use crate::TelemetryClient;
+
+#[derive(Clone)]
+enum FieldValue {
+    Measurement(f64),
+    Property(String),
+}
+
+/// Recorded at span creation and mutated as fields are recorded, then turned into
+/// telemetry when the span closes.
+#[derive(Default)]
+struct SpanData {
+    fields: Vec<(&'static str, FieldValue)>,
+    started_at: Option<chrono::DateTime<chrono::Utc>>,
+    correlation_id: String,
+    parent_correlation_id: Option<String>,
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(&'static str, FieldValue)>);
+
+impl<'a> tracing::field::Visit for FieldVisitor<'a> {
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.0.push((field.name(), FieldValue::Measurement(value)));
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.0.push((field.name(), FieldValue::Measurement(value as f64)));
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.0.push((field.name(), FieldValue::Measurement(value as f64)));
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.0.push((field.name(), FieldValue::Property(value.to_string())));
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.push((field.name(), FieldValue::Property(format!("{:?}", value))));
+    }
+}
+
+/// Submits an [`AvailabilityTelemetry`] item to a [`TelemetryClient`] each time a `tracing`
+/// span closes. The span's name and elapsed time become `name` and `duration`; numeric
+/// fields become `measurements` and the rest become `properties`; an `error` field marks the
+/// telemetry as unsuccessful, unless it was explicitly recorded as `false` (the common
+/// `#[instrument(fields(error))]` + `Span::current().record("error", false)` idiom for "no
+/// error occurred").
+pub struct AppInsightsLayer {
+    client: TelemetryClient,
+}
+
+impl AppInsightsLayer {
+    /// Creates a layer that submits telemetry through `client` as spans close.
+    pub fn new(client: TelemetryClient) -> Self {
+        Self { client }
+    }
+}
+
+impl<S> Layer<S> for AppInsightsLayer
+where
+    S: Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in on_new_span");
+
+        let parent_correlation_id = span.parent().map(|parent| correlation_id(&parent.id()));
+
+        let mut data = SpanData {
+            started_at: Some(time::now()),
+            correlation_id: correlation_id(id),
+            parent_correlation_id,
+            ..Default::default()
+        };
+        attrs.record(&mut FieldVisitor(&mut data.fields));
+
+        span.extensions_mut().insert(data);
+    }
+
+    fn on_record(&self, id: &Id, values: &tracing::span::Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in on_record");
+        let mut extensions = span.extensions_mut();
+        if let Some(data) = extensions.get_mut::<SpanData>() {
+            values.record(&mut FieldVisitor(&mut data.fields));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(&id) {
+            Some(span) => span,
+            None => return,
+        };
+
+        let data = match span.extensions_mut().remove::<SpanData>() {
+            Some(data) => data,
+            None => return,
+        };
+
+        let started_at = data.started_at.unwrap_or_else(time::now);
+        let elapsed = (time::now() - started_at)
+            .to_std()
+            .unwrap_or_else(|_| StdDuration::from_secs(0));
+
+        let success = match data.fields.iter().find(|(name, _)| *name == "error") {
+            None => true,
+            Some((_, FieldValue::Property(value))) => value == "false",
+            Some((_, FieldValue::Measurement(_))) => false,
+        };
+
+        let mut telemetry = AvailabilityTelemetry::new(span.name().to_string(), elapsed, success);
+        for (name, value) in data.fields {
+            match value {
+                FieldValue::Measurement(value) => {
+                    telemetry.measurements_mut().insert(name.to_string(), value);
+                }
+                FieldValue::Property(value) => {
+                    telemetry.properties_mut().insert(name.to_string(), value);
+                }
+            }
+        }
+
+        telemetry.tags_mut().insert("ai.operation.id".into(), data.correlation_id);
+        if let Some(parent_correlation_id) = data.parent_correlation_id {
+            telemetry
+                .tags_mut()
+                .insert("ai.operation.parentId".into(), parent_correlation_id);
+        }
+
+        self.client.track(telemetry);
+    }
+}
+
+/// Derives a correlation id for a span from its `tracing::Id`. A span's telemetry carries its
+/// own id as the operation id and its parent span's id as the parent id, which is how a child
+/// is correlated with its parent. The operation id is not shared between a span and its
+/// children, so each span's sampling decision (see [`crate::telemetry::sampler`]) is made
+/// independently of its parent's.
+fn correlation_id(id: &Id) -> String {
+    format!("{:016x}", id.into_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+    use crate::channel::Transmitter;
+    use crate::config::TelemetryConfig;
+    use crate::contracts::{AvailabilityData, Base, Data, Envelope};
+
+    #[derive(Default)]
+    struct RecordingTransmitter {
+        sent: Mutex<Vec<Envelope>>,
+    }
+
+    impl Transmitter for Arc<RecordingTransmitter> {
+        fn send(&self, envelopes: Vec<Envelope>) {
+            self.sent.lock().unwrap().extend(envelopes);
+        }
+    }
+
+    fn availability_data(envelope: &Envelope) -> &AvailabilityData {
+        match &envelope.data {
+            Base::Data(Data::AvailabilityData(data)) => data,
+            _ => panic!("expected availability data"),
+        }
+    }
+
+    fn run_with_layer(f: impl FnOnce()) -> Arc<RecordingTransmitter> {
+        let transmitter = Arc::new(RecordingTransmitter::default());
+        let client = TelemetryClient::with_transmitter(TelemetryConfig::new("instrumentation".into()), transmitter.clone());
+        let subscriber = tracing_subscriber::registry().with(AppInsightsLayer::new(client.clone()));
+
+        tracing::subscriber::with_default(subscriber, f);
+
+        client.flush();
+        transmitter
+    }
+
+    #[test]
+    fn it_succeeds_when_no_error_field_is_recorded() {
+        let transmitter = run_with_layer(|| {
+            let span = tracing::info_span!("probe");
+            let _guard = span.enter();
+        });
+
+        let sent = transmitter.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(availability_data(&sent[0]).success);
+    }
+
+    #[test]
+    fn it_succeeds_when_the_error_field_is_explicitly_recorded_false() {
+        let transmitter = run_with_layer(|| {
+            let span = tracing::info_span!("probe", error = tracing::field::Empty);
+            let _guard = span.enter();
+            span.record("error", false);
+        });
+
+        let sent = transmitter.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(availability_data(&sent[0]).success);
+    }
+
+    #[test]
+    fn it_fails_when_the_error_field_is_recorded_true() {
+        let transmitter = run_with_layer(|| {
+            let span = tracing::info_span!("probe", error = tracing::field::Empty);
+            let _guard = span.enter();
+            span.record("error", true);
+        });
+
+        let sent = transmitter.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(!availability_data(&sent[0]).success);
+    }
+
+    #[test]
+    fn it_fails_when_an_error_field_carries_a_debug_value() {
+        let transmitter = run_with_layer(|| {
+            let span = tracing::info_span!("probe", error = tracing::field::Empty);
+            let _guard = span.enter();
+            span.record("error", tracing::field::debug("boom"));
+        });
+
+        let sent = transmitter.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(!availability_data(&sent[0]).success);
+    }
+
+    #[test]
+    fn it_correlates_a_child_span_with_its_parent() {
+        let transmitter = run_with_layer(|| {
+            let parent = tracing::info_span!("parent");
+            let _parent_guard = parent.enter();
+            {
+                let child = tracing::info_span!("child");
+                let _child_guard = child.enter();
+            }
+        });
+
+        let sent = transmitter.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+
+        let child = sent
+            .iter()
+            .find(|envelope| envelope.tags.contains_key("ai.operation.parentId"))
+            .expect("child span should have a parent correlation id");
+        assert_ne!(child.tags["ai.operation.id"], child.tags["ai.operation.parentId"]);
+    }
+}
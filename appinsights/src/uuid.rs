@@ -0,0 +1,4 @@
+//! Re-exports the `uuid` crate's `Uuid` under a crate-local path, so the rest of the crate
+//! depends on `crate::uuid::Uuid` rather than the external crate directly.
+
+pub use uuid::Uuid;